@@ -0,0 +1,227 @@
+//! `#[bitfield(size = N)]` generates a named, `no_std`-friendly register
+//! view over a `[u8; N]` buffer, in the spirit of `tartan-bitfield`.
+//!
+//! Annotate a struct with `#[bitfield(size = N)]` and each field with
+//! either `#[bits(a..b)]` (a multi-bit field) or `#[bit(n)]` (a single
+//! boolean bit). The macro replaces the struct with a tuple struct
+//! wrapping `[u8; N]` and generates `get_*`/`set_*` methods that delegate
+//! to [`BitfieldAccess::read_field`]/[`BitfieldAccess::write_field`] from
+//! the `bitfield-access` crate.
+//!
+//! A `#[bits(..)]` field's type may be anything implementing
+//! `From<RawInt>` (for the getter) and `Into<RawInt>` (for the setter),
+//! where `RawInt` is the narrowest unsigned integer that fits the bit
+//! range (`u8` for 1-8 bits, `u16` for 9-16, and so on) — so enums and
+//! nested bitfield structs work as field types, not just integers.
+//!
+//! This means the field's type must match `RawInt` *exactly*, not just be
+//! wide enough to hold it: `u32: From<u16>` exists in `core` (a widening
+//! conversion), so a `u32` field over a 9-16-bit range compiles for the
+//! getter, but `u32: Into<u16>` does not (narrowing conversions aren't
+//! `Into`), so the setter fails to compile. Pick a field type that's
+//! exactly as wide as `RawInt` for the range you declare, or a custom type
+//! with its own `From`/`Into` impls.
+//!
+//! ```
+//! use bitfield_access::BitfieldAccess;
+//! use bitfield_access_derive::bitfield;
+//!
+//! #[bitfield(size = 4)]
+//! pub struct ControlRegister {
+//!     #[bits(12..24)]
+//!     pub version: u16,
+//!     #[bit(31)]
+//!     pub enabled: bool,
+//! }
+//!
+//! let mut reg = ControlRegister::new([0; 4]);
+//! reg.set_version(0x456);
+//! reg.set_enabled(true);
+//! assert_eq!(reg.get_version(), 0x456);
+//! assert!(reg.get_enabled());
+//! assert_eq!(reg.into_inner(), [0x00, 0x04, 0x56, 0x01]);
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Expr, ExprRange, Field, Fields, ItemStruct, LitInt, RangeLimits, Token,
+};
+
+struct BitfieldArgs {
+    size: usize,
+}
+
+impl Parse for BitfieldArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let mut size = None;
+        for pair in &pairs {
+            if pair.path.is_ident("size") {
+                size = Some(lit_int_value(&pair.value)?);
+            }
+        }
+        let size = size.ok_or_else(|| {
+            syn::Error::new(input.span(), "#[bitfield(...)] requires a `size = N` argument")
+        })?;
+        Ok(BitfieldArgs { size })
+    }
+}
+
+fn lit_int_value(expr: &Expr) -> syn::Result<usize> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            syn::Lit::Int(lit_int) => lit_int.base10_parse(),
+            _ => Err(syn::Error::new(expr_lit.span(), "expected an integer literal")),
+        },
+        _ => Err(syn::Error::new(expr.span(), "expected an integer literal")),
+    }
+}
+
+/// The narrowest unsigned integer type that `read_field`/`write_field`
+/// can hold `width` bits in.
+fn raw_type_for_width(width: usize) -> syn::Result<syn::Type> {
+    let name = match width {
+        0 => return Err(syn::Error::new(proc_macro2::Span::call_site(), "field has zero width")),
+        1..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        65..=128 => "u128",
+        _ => return Err(syn::Error::new(proc_macro2::Span::call_site(), "field wider than 128 bits")),
+    };
+    syn::parse_str(name)
+}
+
+fn range_bounds(range: &ExprRange) -> syn::Result<(usize, usize)> {
+    let start = range
+        .start
+        .as_ref()
+        .ok_or_else(|| syn::Error::new(range.span(), "bit range must have a start bound"))
+        .and_then(|e| lit_int_value(e))?;
+    let mut end = range
+        .end
+        .as_ref()
+        .ok_or_else(|| syn::Error::new(range.span(), "bit range must have an end bound"))
+        .and_then(|e| lit_int_value(e))?;
+    if matches!(range.limits, RangeLimits::Closed(_)) {
+        end += 1;
+    }
+    Ok((start, end))
+}
+
+fn field_accessor(field: &Field) -> syn::Result<TokenStream2> {
+    let field_ident = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| syn::Error::new(field.span(), "#[bitfield] requires named fields"))?;
+    let field_ty = &field.ty;
+    let get_ident = format_ident!("get_{}", field_ident);
+    let set_ident = format_ident!("set_{}", field_ident);
+
+    let bits_attr = field.attrs.iter().find(|a| a.path().is_ident("bits"));
+    let bit_attr = field.attrs.iter().find(|a| a.path().is_ident("bit"));
+
+    if let Some(attr) = bits_attr {
+        let range: ExprRange = attr.parse_args()?;
+        let (start, end) = range_bounds(&range)?;
+        let raw_ty = raw_type_for_width(end - start)?;
+        Ok(quote! {
+            pub fn #get_ident(&self) -> #field_ty {
+                <#field_ty as ::core::convert::From<#raw_ty>>::from(
+                    ::bitfield_access::BitfieldAccess::read_field::<#raw_ty>(&self.0, #start..#end),
+                )
+            }
+
+            pub fn #set_ident(&mut self, value: #field_ty) {
+                let raw: #raw_ty = ::core::convert::Into::<#raw_ty>::into(value);
+                ::bitfield_access::BitfieldAccess::write_field(&mut self.0, #start..#end, raw);
+            }
+        })
+    } else if let Some(attr) = bit_attr {
+        let lit: LitInt = attr.parse_args()?;
+        let n = lit.base10_parse::<usize>()?;
+        let end = n + 1;
+        Ok(quote! {
+            pub fn #get_ident(&self) -> bool {
+                ::bitfield_access::BitfieldAccess::read_field::<u8>(&self.0, #n..#end) != 0
+            }
+
+            pub fn #set_ident(&mut self, value: bool) {
+                ::bitfield_access::BitfieldAccess::write_field(&mut self.0, #n..#end, value as u8);
+            }
+        })
+    } else {
+        Err(syn::Error::new(
+            field.span(),
+            "field requires a `#[bits(a..b)]` or `#[bit(n)]` attribute",
+        ))
+    }
+}
+
+fn expand(args: BitfieldArgs, input: ItemStruct) -> syn::Result<TokenStream2> {
+    let vis = &input.vis;
+    let attrs = &input.attrs;
+    let ident = &input.ident;
+    let size = args.size;
+
+    let Fields::Named(fields) = &input.fields else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "#[bitfield] requires a struct with named fields",
+        ));
+    };
+
+    let accessors = fields
+        .named
+        .iter()
+        .map(field_accessor)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis struct #ident([u8; #size]);
+
+        impl #ident {
+            #vis fn new(buffer: [u8; #size]) -> Self {
+                Self(buffer)
+            }
+
+            #vis fn into_inner(self) -> [u8; #size] {
+                self.0
+            }
+
+            #(#accessors)*
+        }
+
+        impl ::core::convert::AsRef<[u8]> for #ident {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsMut<[u8]> for #ident {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+    })
+}
+
+/// See the crate-level docs.
+#[proc_macro_attribute]
+pub fn bitfield(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as BitfieldArgs);
+    let input = parse_macro_input!(item as ItemStruct);
+
+    expand(args, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
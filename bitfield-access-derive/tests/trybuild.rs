@@ -0,0 +1,6 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/roundtrip.rs");
+    t.compile_fail("tests/ui/narrow_setter.rs");
+}
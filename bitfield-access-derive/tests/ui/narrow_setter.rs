@@ -0,0 +1,14 @@
+// A field type wider than the bit range's narrowest raw type fails to
+// compile: the getter works (widening `u32: From<u16>` exists), but the
+// setter needs `u32: Into<u16>`, which std doesn't provide for narrowing
+// integer conversions. See the crate docs for the "narrowest raw type
+// only" constraint this is exercising.
+use bitfield_access_derive::bitfield;
+
+#[bitfield(size = 4)]
+pub struct Mismatched {
+    #[bits(12..24)]
+    pub version: u32,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use bitfield_access_derive::bitfield;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Idle,
+    Active,
+}
+
+impl From<u8> for Mode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Mode::Idle,
+            _ => Mode::Active,
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(value: Mode) -> Self {
+        match value {
+            Mode::Idle => 0,
+            Mode::Active => 1,
+        }
+    }
+}
+
+#[bitfield(size = 4)]
+pub struct ControlRegister {
+    #[bits(12..24)]
+    pub version: u16,
+    #[bit(31)]
+    pub enabled: bool,
+    #[bits(0..1)]
+    pub mode: Mode,
+}
+
+fn main() {
+    let mut reg = ControlRegister::new([0; 4]);
+
+    reg.set_version(0x456);
+    assert_eq!(reg.get_version(), 0x456);
+
+    reg.set_enabled(true);
+    assert!(reg.get_enabled());
+    reg.set_enabled(false);
+    assert!(!reg.get_enabled());
+
+    reg.set_mode(Mode::Active);
+    assert_eq!(reg.get_mode(), Mode::Active);
+    reg.set_mode(Mode::Idle);
+    assert_eq!(reg.get_mode(), Mode::Idle);
+
+    // version survived the enabled/mode round-trips untouched.
+    assert_eq!(reg.get_version(), 0x456);
+
+    let _: [u8; 4] = reg.into_inner();
+}
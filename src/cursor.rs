@@ -0,0 +1,213 @@
+//! Stateful sequential bit cursors built on top of [`BitfieldAccess`].
+
+use core::fmt::{Debug, UpperHex};
+
+use num::{traits::CheckedShr, PrimInt, Unsigned};
+
+use crate::BitfieldAccess;
+
+/// A cursor for reading a packed bitstream sequentially, without having to
+/// track absolute bit offsets by hand.
+///
+/// Each call to [`read_next`](BitReader::read_next) reads the next `bits`
+/// bits (MSB-first, per [`BitfieldAccess::read_field`]) and advances the
+/// cursor's position by that many bits.
+///
+/// # Examples
+///
+/// ```
+/// use bitfield_access::BitReader;
+///
+/// let buffer = [0x12, 0x34, 0x56, 0x78];
+/// let mut reader = BitReader::new(&buffer);
+/// assert_eq!(reader.read_next::<u8>(4), 0x1);
+/// assert_eq!(reader.read_next::<u8>(4), 0x2);
+/// assert_eq!(reader.read_next::<u16>(16), 0x3456);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a new reader positioned at the start of `data`.
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        BitReader { data, pos: 0 }
+    }
+
+    /// Read the next `bits` bits and advance the cursor past them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is wider than `T` or if fewer than `bits` bits
+    /// remain in the buffer.
+    #[inline]
+    pub fn read_next<T>(&mut self, bits: usize) -> T
+    where
+        T: PrimInt + Unsigned,
+    {
+        let value = self.data.read_field::<T>(self.pos..self.pos + bits);
+        self.pos += bits;
+        value
+    }
+
+    /// Move the cursor to an absolute bit position.
+    #[inline]
+    pub fn seek(&mut self, bit: usize) {
+        self.pos = bit;
+    }
+
+    /// Advance the cursor to the start of the next byte, if it isn't
+    /// already byte-aligned.
+    #[inline]
+    pub fn align_to_byte(&mut self) {
+        self.pos = self.pos.div_ceil(8) * 8;
+    }
+
+    /// The number of bits remaining between the cursor and the end of the
+    /// buffer.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.data.len() * 8 - self.pos
+    }
+
+    /// The cursor's current absolute bit position.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reset the cursor to the start of the buffer.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+}
+
+/// A cursor for writing a packed bitstream sequentially, without having to
+/// track absolute bit offsets by hand.
+///
+/// Each call to [`write_next`](BitWriter::write_next) writes `bits` bits
+/// (MSB-first, per [`BitfieldAccess::write_field`]) and advances the
+/// cursor's position by that many bits.
+///
+/// # Examples
+///
+/// ```
+/// use bitfield_access::BitWriter;
+///
+/// let mut buffer = [0u8; 4];
+/// let mut writer = BitWriter::new(&mut buffer);
+/// writer.write_next(4, 0x1_u8);
+/// writer.write_next(4, 0x2_u8);
+/// writer.write_next(16, 0x3456_u16);
+/// assert_eq!(buffer, [0x12, 0x34, 0x56, 0x00]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    data: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Create a new writer positioned at the start of `data`.
+    #[inline]
+    pub fn new(data: &'a mut [u8]) -> Self {
+        BitWriter { data, pos: 0 }
+    }
+
+    /// Write `value` into the next `bits` bits and advance the cursor past
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` doesn't fit in `bits` bits or if fewer than `bits`
+    /// bits remain in the buffer.
+    #[inline]
+    pub fn write_next<T>(&mut self, bits: usize, value: T)
+    where
+        T: PrimInt + Unsigned + TryInto<u8> + UpperHex + CheckedShr,
+        <T as TryInto<u8>>::Error: Debug,
+    {
+        self.data.write_field(self.pos..self.pos + bits, value);
+        self.pos += bits;
+    }
+
+    /// Move the cursor to an absolute bit position.
+    #[inline]
+    pub fn seek(&mut self, bit: usize) {
+        self.pos = bit;
+    }
+
+    /// Advance the cursor to the start of the next byte, if it isn't
+    /// already byte-aligned.
+    #[inline]
+    pub fn align_to_byte(&mut self) {
+        self.pos = self.pos.div_ceil(8) * 8;
+    }
+
+    /// The number of bits remaining between the cursor and the end of the
+    /// buffer.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.data.len() * 8 - self.pos
+    }
+
+    /// The cursor's current absolute bit position.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reset the cursor to the start of the buffer.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_reader() {
+        let buffer = [0x12, 0x34, 0x56, 0x78];
+        let mut reader = BitReader::new(&buffer);
+
+        assert_eq!(reader.read_next::<u8>(4), 0x1);
+        assert_eq!(reader.read_next::<u8>(4), 0x2);
+        assert_eq!(reader.position(), 8);
+        assert_eq!(reader.read_next::<u16>(16), 0x3456);
+        assert_eq!(reader.remaining_bits(), 8);
+
+        reader.seek(0);
+        assert_eq!(reader.read_next::<u32>(32), 0x12345678);
+
+        reader.reset();
+        reader.read_next::<u8>(4);
+        reader.align_to_byte();
+        assert_eq!(reader.position(), 8);
+    }
+
+    #[test]
+    fn test_bit_writer() {
+        let mut buffer = [0u8; 4];
+        let mut writer = BitWriter::new(&mut buffer);
+
+        writer.write_next(4, 0x1_u8);
+        writer.write_next(4, 0x2_u8);
+        writer.write_next(16, 0x3456_u16);
+        assert_eq!(buffer, [0x12, 0x34, 0x56, 0x00]);
+
+        let mut buffer = [0u8; 4];
+        let mut writer = BitWriter::new(&mut buffer);
+        writer.write_next(4, 0x1_u8);
+        writer.align_to_byte();
+        assert_eq!(writer.position(), 8);
+        writer.write_next(8, 0xAB_u8);
+        assert_eq!(buffer, [0x10, 0xAB, 0x00, 0x00]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Opt-in auto-growing reads/writes for `Vec<u8>`-backed buffers.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, UpperHex},
+    ops::{Bound, RangeBounds},
+};
+
+use num::{traits::CheckedShr, PrimInt, Unsigned};
+
+use crate::BitfieldAccess;
+
+/// Auto-growing counterparts to [`BitfieldAccess::read_field`]/
+/// [`BitfieldAccess::write_field`] for `Vec<u8>`-backed buffers.
+///
+/// Unlike the fixed-size [`BitfieldAccess`] methods, these never panic on
+/// an out-of-bounds bit range: [`write_field_grow`](GrowableBitfieldAccess::write_field_grow)
+/// resizes the vector with zero bytes to fit, and
+/// [`read_field_grow`](GrowableBitfieldAccess::read_field_grow) treats
+/// bits past the current length as zero.
+pub trait GrowableBitfieldAccess {
+    /// Write a bitfield, growing the buffer with zero bytes first if the
+    /// bit range extends past its current length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::vec::Vec;
+    /// use bitfield_access::GrowableBitfieldAccess;
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// buffer.write_field_grow(4..8, 0xA_u8);
+    /// assert_eq!(buffer, [0x0A]);
+    /// buffer.write_field_grow(12..20, 0xBC_u8);
+    /// assert_eq!(buffer, [0x0A, 0x0B, 0xC0]);
+    /// ```
+    fn write_field_grow<T>(&mut self, bitrange: impl RangeBounds<usize>, value: T)
+    where
+        T: PrimInt + Unsigned + TryInto<u8> + UpperHex + CheckedShr,
+        <T as TryInto<u8>>::Error: Debug;
+
+    /// Read a bitfield, treating any bits past the buffer's current
+    /// length as zero instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::vec::Vec;
+    /// use bitfield_access::GrowableBitfieldAccess;
+    ///
+    /// let buffer: Vec<u8> = alloc::vec![0x12];
+    /// assert_eq!(buffer.read_field_grow::<u16>(4..20), 0x2000);
+    /// ```
+    fn read_field_grow<T>(&self, bitrange: impl RangeBounds<usize>) -> T
+    where
+        T: PrimInt + Unsigned;
+}
+
+impl GrowableBitfieldAccess for Vec<u8> {
+    fn write_field_grow<T>(&mut self, bitrange: impl RangeBounds<usize>, value: T)
+    where
+        T: PrimInt + Unsigned + TryInto<u8> + UpperHex + CheckedShr,
+        <T as TryInto<u8>>::Error: Debug,
+    {
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => self.len() * 8,
+        };
+        let needed_bytes = end.div_ceil(8);
+        if needed_bytes > self.len() {
+            self.resize(needed_bytes, 0);
+        }
+        self.write_field(bitrange, value);
+    }
+
+    fn read_field_grow<T>(&self, bitrange: impl RangeBounds<usize>) -> T
+    where
+        T: PrimInt + Unsigned,
+    {
+        let start = match bitrange.start_bound() {
+            Bound::Included(idx) => *idx,
+            Bound::Excluded(idx) => *idx + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => self.len() * 8,
+        };
+        let needed_bytes = end.div_ceil(8);
+        if needed_bytes <= self.len() {
+            self.read_field(start..end)
+        } else {
+            let mut padded = self.clone();
+            padded.resize(needed_bytes, 0);
+            padded.read_field(start..end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_write_field_grow() {
+        let mut buffer: Vec<u8> = Vec::new();
+        buffer.write_field_grow(4..8, 0xA_u8);
+        assert_eq!(buffer, vec![0x0A]);
+
+        buffer.write_field_grow(12..20, 0xBC_u8);
+        assert_eq!(buffer, vec![0x0A, 0x0B, 0xC0]);
+
+        // Writing within the already-grown region doesn't resize again.
+        buffer.write_field_grow(0..4, 0x1_u8);
+        assert_eq!(buffer, vec![0x1A, 0x0B, 0xC0]);
+    }
+
+    #[test]
+    fn test_read_field_grow() {
+        let buffer: Vec<u8> = vec![0x12];
+
+        // Fully within bounds behaves like `read_field`.
+        assert_eq!(buffer.read_field_grow::<u8>(4..8), 0x2);
+
+        // Partially out of bounds is zero-extended.
+        assert_eq!(buffer.read_field_grow::<u16>(4..20), 0x2000);
+
+        // Entirely out of bounds reads as zero.
+        assert_eq!(buffer.read_field_grow::<u8>(16..24), 0x0);
+    }
+}
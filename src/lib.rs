@@ -6,7 +6,21 @@ use core::{
     ops::{Bound, RangeBounds},
 };
 
-use num::{traits::CheckedShr, PrimInt, Unsigned};
+use num::{traits::CheckedShr, PrimInt, Signed, Unsigned};
+
+mod cursor;
+pub use cursor::{BitReader, BitWriter};
+
+/// Re-exports the `#[bitfield(size = N)]` attribute macro from
+/// `bitfield-access-derive` for generating named register views. Enable
+/// with the `derive` feature.
+#[cfg(feature = "derive")]
+pub use bitfield_access_derive::bitfield;
+
+#[cfg(feature = "alloc")]
+mod growable;
+#[cfg(feature = "alloc")]
+pub use growable::GrowableBitfieldAccess;
 
 #[inline]
 fn bitmask<T: PrimInt + Unsigned>(bit_width: usize) -> T {
@@ -15,7 +29,11 @@ fn bitmask<T: PrimInt + Unsigned>(bit_width: usize) -> T {
     if bit_width == max_width {
         T::max_value()
     } else {
-        T::from((1_usize << bit_width) - 1).unwrap()
+        // Shift `T` itself rather than staging through `usize`: on
+        // platforms where `usize` is narrower than `T` (e.g. `T = u128`
+        // on a 64-bit target), `1_usize << bit_width` can overflow even
+        // though the shift is perfectly valid for `T`.
+        (T::one() << bit_width) - T::one()
     }
 }
 
@@ -142,6 +160,393 @@ pub trait BitfieldAccess: AsRef<[u8]> {
             value = value.checked_shr(bit_width as u32).unwrap_or(zero);
         }
     }
+
+    /// Read a bitfield with the given bit indices from a buffer, using LSB0 bit
+    /// numbering: bit 0 is the least-significant bit of byte 0, and multi-byte
+    /// fields are assembled little-endian (bit `start` is the lowest-order bit
+    /// of the result).
+    ///
+    /// This is the mirror image of [`read_field`](BitfieldAccess::read_field),
+    /// which numbers bit 0 as the most-significant bit of byte 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let buffer = [0x12, 0x34, 0x56, 0x78];
+    /// assert_eq!(buffer.read_field_lsb0::<u8>(0..4), 0x2);
+    /// assert_eq!(buffer.read_field_lsb0::<u16>(4..16), 0x341);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of bits is wider than the integer type `T`
+    /// or the bit indices are out of bounds.
+    #[inline]
+    fn read_field_lsb0<T>(&self, bitrange: impl RangeBounds<usize>) -> T
+    where
+        T: PrimInt + Unsigned,
+    {
+        let data = self.as_ref();
+        let start = match bitrange.start_bound() {
+            Bound::Included(idx) => *idx,
+            Bound::Excluded(idx) => *idx + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => data.len() * 8,
+        };
+
+        let storage_width = 8 * core::mem::size_of::<T>();
+        let bit_width = end - start;
+        assert!(
+            bit_width <= storage_width,
+            "field width {} exceeds storage width {}",
+            bit_width,
+            storage_width
+        );
+        let first_byte = start / 8;
+        let last_byte = (end - 1) / 8;
+        assert!(
+            last_byte < data.len(),
+            "bit index {} is out of bounds for a {}-byte buffer",
+            end - 1,
+            data.len()
+        );
+
+        let mut result = T::zero();
+        for (i, &byte) in data.iter().enumerate().take(last_byte + 1).skip(first_byte) {
+            let byte_lo = i * 8;
+            let local_start = core::cmp::max(start, byte_lo) - byte_lo;
+            let local_end = core::cmp::min(end, byte_lo + 8) - byte_lo;
+            let width = local_end - local_start;
+            let chunk = (byte >> local_start) & bitmask::<u8>(width);
+            let shift = core::cmp::max(start, byte_lo) - start;
+            result = result | T::from(chunk).unwrap() << shift;
+        }
+
+        result
+    }
+
+    /// Write a bitfield with the given bit indices to a buffer, using LSB0 bit
+    /// numbering: bit 0 is the least-significant bit of byte 0, and multi-byte
+    /// fields are assembled little-endian (bit `start` is the lowest-order bit
+    /// of `value`).
+    ///
+    /// This is the mirror image of [`write_field`](BitfieldAccess::write_field),
+    /// which numbers bit 0 as the most-significant bit of byte 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let mut buffer = [0x12, 0x34, 0x56, 0x78];
+    /// buffer.write_field_lsb0(0..4, 0xA_u8);
+    /// assert_eq!(buffer, [0x1A, 0x34, 0x56, 0x78]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit indices are out of bounds or the value is too large.
+    #[inline]
+    fn write_field_lsb0<T>(&mut self, bitrange: impl RangeBounds<usize>, value: T)
+    where
+        Self: AsMut<[u8]>,
+        T: PrimInt + Unsigned + TryInto<u8> + UpperHex + CheckedShr,
+        <T as TryInto<u8>>::Error: Debug,
+    {
+        let data = self.as_mut();
+        let start = match bitrange.start_bound() {
+            Bound::Included(idx) => *idx,
+            Bound::Excluded(idx) => *idx + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => data.len() * 8,
+        };
+        let first_byte = start / 8;
+        let last_byte = (end - 1) / 8;
+        assert!(
+            last_byte < data.len(),
+            "bit index {} is out of bounds for a {}-byte buffer",
+            end - 1,
+            data.len()
+        );
+        let max_value = bitmask(end - start);
+        assert!(
+            value <= max_value,
+            "value {:#X} exceeds maximum field value {:#X}",
+            value,
+            max_value
+        );
+
+        let byte_mask = T::from(0xFF).unwrap();
+        let zero = T::zero();
+
+        for (i, byte) in data.iter_mut().enumerate().take(last_byte + 1).skip(first_byte) {
+            let byte_lo = i * 8;
+            let local_start = core::cmp::max(start, byte_lo) - byte_lo;
+            let local_end = core::cmp::min(end, byte_lo + 8) - byte_lo;
+            let width = local_end - local_start;
+            let shift = core::cmp::max(start, byte_lo) - start;
+            let bit_mask = bitmask::<u8>(width) << local_start;
+            let new_bits: u8 = (value.checked_shr(shift as u32).unwrap_or(zero) & byte_mask)
+                .try_into()
+                .unwrap();
+            *byte = (*byte & !bit_mask) | ((new_bits << local_start) & bit_mask);
+        }
+    }
+
+    /// Read a signed bitfield with the given bit indices from a buffer,
+    /// sign-extending the result to the full width of `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let buffer = [0b1111_0000, 0x00];
+    /// assert_eq!(buffer.read_field_signed::<i8>(0..4), -1);
+    /// assert_eq!(buffer.read_field_signed::<i8>(4..8), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of bits is wider than the integer type `S`
+    /// or the bit indices are out of bounds.
+    #[inline]
+    fn read_field_signed<S>(&self, bitrange: impl RangeBounds<usize>) -> S
+    where
+        S: PrimInt + Signed,
+    {
+        let data = self.as_ref();
+        let start = match bitrange.start_bound() {
+            Bound::Included(idx) => *idx,
+            Bound::Excluded(idx) => *idx + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => data.len() * 8,
+        };
+
+        let storage_width = 8 * core::mem::size_of::<S>();
+        let bit_width = end - start;
+        assert!(
+            bit_width <= storage_width,
+            "field width {} exceeds storage width {}",
+            bit_width,
+            storage_width
+        );
+
+        let raw: u128 = data.read_field(start..end);
+        let sign_bit = 1_u128 << (bit_width - 1);
+        let extended = if raw & sign_bit != 0 {
+            raw | !bitmask::<u128>(bit_width)
+        } else {
+            raw
+        };
+
+        S::from(extended as i128).unwrap()
+    }
+
+    /// Write a signed bitfield with the given bit indices to a buffer.
+    ///
+    /// `value` is masked to `bit_width` bits (two's complement) before
+    /// being written, so it is sign-extended back out by a matching
+    /// [`read_field_signed`](BitfieldAccess::read_field_signed) call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let mut buffer = [0x00_u8];
+    /// buffer.write_field_signed(0..4, -1_i8);
+    /// assert_eq!(buffer, [0b1111_0000]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bit indices are out of bounds or `value` doesn't fit
+    /// in the field's two's-complement range.
+    #[inline]
+    fn write_field_signed<S>(&mut self, bitrange: impl RangeBounds<usize>, value: S)
+    where
+        Self: AsMut<[u8]>,
+        S: PrimInt + Signed,
+    {
+        let data = self.as_ref();
+        let start = match bitrange.start_bound() {
+            Bound::Included(idx) => *idx,
+            Bound::Excluded(idx) => *idx + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bitrange.end_bound() {
+            Bound::Included(idx) => *idx + 1,
+            Bound::Excluded(idx) => *idx,
+            Bound::Unbounded => data.len() * 8,
+        };
+        let bit_width = end - start;
+
+        let value = value.to_i128().unwrap();
+        // A full-width 128-bit field's range is exactly `i128::MIN..=i128::MAX`;
+        // computing it as `-(1 << 127)` would overflow on the negation.
+        let (min, max) = if bit_width == 128 {
+            (i128::MIN, i128::MAX)
+        } else {
+            (-(1_i128 << (bit_width - 1)), (1_i128 << (bit_width - 1)) - 1)
+        };
+        assert!(
+            (min..=max).contains(&value),
+            "value {} exceeds signed range {}..={} for a {}-bit field",
+            value,
+            min,
+            max,
+            bit_width
+        );
+
+        let masked = (value as u128) & bitmask::<u128>(bit_width);
+        self.write_field::<u128>(start..end, masked);
+    }
+
+    /// Pack a slice of equal-width values into the buffer starting at
+    /// `start_bit`, with no padding between values (the same MSB-first
+    /// numbering as [`write_field`](BitfieldAccess::write_field)).
+    ///
+    /// Unlike calling [`write_field`](BitfieldAccess::write_field) once per
+    /// value, this carries a rolling accumulator across the whole slice so
+    /// each output byte is only touched once.
+    ///
+    /// `start_bit` must be byte-aligned (a multiple of 8): unlike
+    /// [`write_field`](BitfieldAccess::write_field), this does not merge
+    /// into a partially-written leading byte, so a non-byte-aligned
+    /// `start_bit` panics rather than silently packing from the wrong bit.
+    /// Byte-align `start_bit` yourself first (e.g. via
+    /// [`BitWriter::align_to_byte`](crate::BitWriter::align_to_byte)) if the
+    /// values don't start on a byte boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let mut buffer = [0u8; 2];
+    /// buffer.pack_fields(0, 4, &[0x1_u8, 0x2, 0x3, 0x4]);
+    /// assert_eq!(buffer, [0x12, 0x34]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_width` is wider than `T`, greater than 64, if
+    /// `start_bit` is not byte-aligned, or if the packed values run past
+    /// the end of the buffer.
+    fn pack_fields<T>(&mut self, start_bit: usize, bit_width: usize, values: &[T])
+    where
+        Self: AsMut<[u8]>,
+        T: PrimInt + Unsigned,
+    {
+        assert!(
+            bit_width <= 64 && bit_width <= 8 * core::mem::size_of::<T>(),
+            "bit_width {} exceeds 64 or the storage width of T",
+            bit_width
+        );
+        assert!(
+            start_bit.is_multiple_of(8),
+            "start_bit {} is not byte-aligned (pack_fields requires a byte-aligned start)",
+            start_bit
+        );
+
+        let data = self.as_mut();
+        let mut byte_pos = start_bit / 8;
+        // Wide enough to hold a full `bit_width` (<= 64) plus up to 7
+        // leftover bits from the previous value without the shift below
+        // ever dropping unflushed high bits.
+        let mut acc: u128 = 0;
+        let mut bits_in_acc: usize = 0;
+
+        for &value in values {
+            acc = (acc << bit_width)
+                | (u128::from(value.to_u64().unwrap()) & bitmask::<u128>(bit_width));
+            bits_in_acc += bit_width;
+
+            while bits_in_acc >= 8 {
+                bits_in_acc -= 8;
+                data[byte_pos] = (acc >> bits_in_acc) as u8;
+                byte_pos += 1;
+            }
+        }
+
+        if bits_in_acc > 0 {
+            let bit_mask = bitmask::<u8>(bits_in_acc) << (8 - bits_in_acc);
+            let leftover = ((acc & bitmask::<u128>(bits_in_acc)) << (8 - bits_in_acc)) as u8;
+            data[byte_pos] = (data[byte_pos] & !bit_mask) | (leftover & bit_mask);
+        }
+    }
+
+    /// Unpack `out.len()` equal-width values from the buffer starting at
+    /// `start_bit`, with no padding between values (the inverse of
+    /// [`pack_fields`](BitfieldAccess::pack_fields)).
+    ///
+    /// `start_bit` must be byte-aligned (a multiple of 8), for the same
+    /// reason as [`pack_fields`](BitfieldAccess::pack_fields).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitfield_access::BitfieldAccess;
+    ///
+    /// let buffer = [0x12, 0x34];
+    /// let mut values = [0_u8; 4];
+    /// buffer.unpack_fields(0, 4, &mut values);
+    /// assert_eq!(values, [0x1, 0x2, 0x3, 0x4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_width` is wider than `T`, greater than 64, if
+    /// `start_bit` is not byte-aligned, or if the unpacked values run past
+    /// the end of the buffer.
+    fn unpack_fields<T>(&self, start_bit: usize, bit_width: usize, out: &mut [T])
+    where
+        T: PrimInt + Unsigned,
+    {
+        assert!(
+            bit_width <= 64 && bit_width <= 8 * core::mem::size_of::<T>(),
+            "bit_width {} exceeds 64 or the storage width of T",
+            bit_width
+        );
+        assert!(
+            start_bit.is_multiple_of(8),
+            "start_bit {} is not byte-aligned (unpack_fields requires a byte-aligned start)",
+            start_bit
+        );
+
+        let data = self.as_ref();
+        let mut byte_pos = start_bit / 8;
+        // See `pack_fields` for why this needs to be wider than `u64`.
+        let mut acc: u128 = 0;
+        let mut bits_in_acc: usize = 0;
+
+        for slot in out.iter_mut() {
+            while bits_in_acc < bit_width {
+                acc = (acc << 8) | u128::from(data[byte_pos]);
+                bits_in_acc += 8;
+                byte_pos += 1;
+            }
+
+            bits_in_acc -= bit_width;
+            let raw = (acc >> bits_in_acc) & bitmask::<u128>(bit_width);
+            *slot = T::from(raw).unwrap();
+        }
+    }
 }
 
 impl<T> BitfieldAccess for T where T: AsRef<[u8]> {}
@@ -200,4 +605,150 @@ mod tests {
         buffer.write_field::<u8>(30..31, 0x1);
         assert_eq!(buffer, [0x13, 0xB4, 0x56, 0x7A]);
     }
+
+    #[test]
+    fn test_read_field_lsb0() {
+        let buffer = [0x12, 0x34, 0x56, 0x78];
+
+        // Test reading within a single byte
+        assert_eq!(buffer.read_field_lsb0::<u8>(0..4), 0x2);
+        assert_eq!(buffer.read_field_lsb0::<u8>(0..8), 0x12);
+
+        // Test reading across byte boundaries
+        assert_eq!(buffer.read_field_lsb0::<u16>(4..16), 0x341);
+
+        // Test reading the entire buffer
+        assert_eq!(buffer.read_field_lsb0::<u32>(..), 0x78563412);
+
+        // Test reading a single bit
+        assert_eq!(buffer.read_field_lsb0::<u8>(0..1), 0x0);
+        assert_eq!(buffer.read_field_lsb0::<u8>(1..=1), 0x1);
+    }
+
+    #[test]
+    fn test_write_field_lsb0() {
+        const BUFFER: [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+
+        // Test writing within a single byte
+        let mut buffer = BUFFER;
+        buffer.write_field_lsb0::<u8>(0..4, 0xA);
+        assert_eq!(buffer, [0x1A, 0x34, 0x56, 0x78]);
+
+        // Test writing across byte boundaries
+        let mut buffer = BUFFER;
+        buffer.write_field_lsb0::<u16>(4..16, 0xABC);
+        assert_eq!(buffer, [0xC2, 0xAB, 0x56, 0x78]);
+
+        // Test writing the entire buffer
+        let mut buffer = BUFFER;
+        buffer.write_field_lsb0::<u32>(.., 0x78563412);
+        assert_eq!(buffer, [0x12, 0x34, 0x56, 0x78]);
+
+        // Test writing a single bit
+        let mut buffer = BUFFER;
+        buffer.write_field_lsb0::<u8>(0..1, 0x1);
+        assert_eq!(buffer, [0x13, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_field_lsb0_out_of_bounds() {
+        let buffer = [0x12_u8];
+        buffer.read_field_lsb0::<u16>(0..16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_field_lsb0_out_of_bounds() {
+        let mut buffer = [0x12_u8];
+        buffer.write_field_lsb0::<u16>(0..16, 0xABCD);
+    }
+
+    #[test]
+    fn test_read_field_signed() {
+        let buffer = [0b1111_0101, 0x7F];
+
+        // Negative value, sign bit set within the field
+        assert_eq!(buffer.read_field_signed::<i8>(0..4), -1);
+        assert_eq!(buffer.read_field_signed::<i16>(0..8), -11);
+
+        // Positive value, sign bit clear
+        assert_eq!(buffer.read_field_signed::<i8>(4..8), 0b0101);
+        assert_eq!(buffer.read_field_signed::<i16>(8..16), 0x7F);
+
+        // Field as wide as the output type
+        assert_eq!(buffer.read_field_signed::<i16>(..), -11_i16 << 8 | 0x7F);
+    }
+
+    #[test]
+    fn test_write_field_signed() {
+        let mut buffer = [0x00_u8];
+        buffer.write_field_signed(0..4, -1_i8);
+        assert_eq!(buffer, [0b1111_0000]);
+
+        let mut buffer = [0x00_u8];
+        buffer.write_field_signed(4..8, -8_i8);
+        assert_eq!(buffer, [0b0000_1000]);
+
+        let mut buffer = [0xFF_u8];
+        buffer.write_field_signed(0..4, 0_i8);
+        assert_eq!(buffer, [0b0000_1111]);
+    }
+
+    #[test]
+    fn test_write_field_signed_full_width() {
+        // A field as wide as the output type's own storage width used to
+        // panic while computing its min bound (`-(1_i128 << 127)` negates
+        // `i128::MIN`'s bit pattern, which overflows).
+        let mut buffer = [0u8; 16];
+        buffer.write_field_signed(.., i128::MIN);
+        assert_eq!(buffer.read_field_signed::<i128>(..), i128::MIN);
+
+        let mut buffer = [0u8; 16];
+        buffer.write_field_signed(.., i128::MAX);
+        assert_eq!(buffer.read_field_signed::<i128>(..), i128::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_field_signed_out_of_range() {
+        let mut buffer = [0x00_u8];
+        buffer.write_field_signed(0..4, 8_i8);
+    }
+
+    #[test]
+    fn test_pack_fields() {
+        // Nibbles packed into whole bytes
+        let mut buffer = [0u8; 2];
+        buffer.pack_fields(0, 4, &[0x1_u8, 0x2, 0x3, 0x4]);
+        assert_eq!(buffer, [0x12, 0x34]);
+
+        // A width that doesn't divide evenly into a byte
+        let mut buffer = [0u8; 2];
+        buffer.pack_fields(0, 3, &[0b101_u8, 0b110, 0b011, 0b001]);
+        assert_eq!(buffer, [0b10111001, 0b1001_0000]);
+
+        // Values wider than a byte
+        let mut buffer = [0u8; 4];
+        buffer.pack_fields(0, 16, &[0x1234_u16, 0x5678]);
+        assert_eq!(buffer, [0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn test_unpack_fields() {
+        let buffer = [0x12, 0x34];
+        let mut values = [0u8; 4];
+        buffer.unpack_fields(0, 4, &mut values);
+        assert_eq!(values, [0x1, 0x2, 0x3, 0x4]);
+
+        let buffer = [0b10111001, 0b1001_0000];
+        let mut values = [0u8; 4];
+        buffer.unpack_fields(0, 3, &mut values);
+        assert_eq!(values, [0b101, 0b110, 0b011, 0b001]);
+
+        let buffer = [0x12, 0x34, 0x56, 0x78];
+        let mut values = [0u16; 2];
+        buffer.unpack_fields(0, 16, &mut values);
+        assert_eq!(values, [0x1234, 0x5678]);
+    }
 }